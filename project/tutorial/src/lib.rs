@@ -0,0 +1,573 @@
+use std::collections::HashMap;
+
+#[cfg(not(target_arch = "wasm32"))]
+use gilrs::{Axis, Button, Gilrs};
+use rand::prelude::*;
+use rusty_engine::prelude::*;
+
+// `thread_rng()` below sources its entropy through the `getrandom` crate. getrandom
+// has no wasm32 backend of its own, so Cargo.toml pulls it in directly with its "js"
+// feature enabled for wasm32, which reads entropy from the browser's crypto API
+// instead of a missing OS RNG.
+
+// ignore small stick drift so a resting pad doesn't creep the player
+const GAMEPAD_DEADZONE: f32 = 0.15;
+
+const THRUST: f32 = 400.0;
+const GRAVITY: Vec2 = Vec2::new(0.0, -500.0);
+const DAMPING: f32 = 0.98;
+
+// how many un-collected ferris it takes to end the game
+const MAX_FERRIS_ON_SCREEN: usize = 10;
+
+// parked far enough off-screen that a hidden text entity never shows up
+const OFFSCREEN: Vec2 = Vec2::new(0.0, 10_000.0);
+
+// seconds each frame of a walk cycle stays on screen
+const ANIMATION_FRAME_SECONDS: f32 = 0.15;
+
+/// Cycles a sprite through a fixed sequence of presets, like a texture-atlas walk cycle.
+struct AnimatedSprite {
+    frames: Vec<SpritePreset>,
+    timer: Timer,
+    index: usize,
+}
+
+impl AnimatedSprite {
+    fn new(frames: Vec<SpritePreset>, frame_seconds: f32) -> Self {
+        Self {
+            frames,
+            timer: Timer::from_seconds(frame_seconds, TimerMode::Repeating),
+            index: 0,
+        }
+    }
+}
+
+/// Everything needed to put the player's sprite back once its respawn (see
+/// `animate_player`) actually takes effect on the following frame.
+struct PendingPlayerRespawn {
+    preset: SpritePreset,
+    translation: Vec2,
+    rotation: f32,
+    scale: f32,
+    layer: f32,
+    collision: bool,
+}
+
+// delay before a held key starts repeating, and the cadence once it does
+const KEY_REPEAT_DELAY_SECONDS: f32 = 0.15;
+const KEY_REPEAT_INTERVAL_SECONDS: f32 = 0.1;
+
+/// Half-transition-count tracking for a single key, plus an optional repeat timer
+/// so a held key can fire at a steady cadence instead of only on the initial tap.
+#[derive(Default)]
+struct TrackedKey {
+    ended_down: bool,
+    half_transitions: u32,
+    repeat_timer: Option<Timer>,
+}
+
+impl TrackedKey {
+    fn pressed_this_frame(&self) -> bool {
+        self.ended_down && self.half_transitions > 0
+    }
+}
+
+/// Per-key edge detection layered on top of the engine's raw pressed state, with
+/// key-repeat support for actions that should fire steadily while held.
+#[derive(Default)]
+struct InputLayer {
+    keys: HashMap<KeyCode, TrackedKey>,
+}
+
+impl InputLayer {
+    /// Updates tracking for `key` from the engine's current state and returns
+    /// whether the bound action should fire this frame (initial tap or repeat).
+    fn tick(&mut self, engine: &Engine, key: KeyCode) -> bool {
+        let currently_down = engine.keyboard_state.pressed(key);
+        let tracked = self.keys.entry(key).or_default();
+
+        tracked.half_transitions = if currently_down != tracked.ended_down {
+            1
+        } else {
+            0
+        };
+        tracked.ended_down = currently_down;
+
+        let just_pressed = tracked.pressed_this_frame();
+        if just_pressed {
+            tracked.repeat_timer = Some(Timer::from_seconds(
+                KEY_REPEAT_DELAY_SECONDS,
+                TimerMode::Once,
+            ));
+        } else if !currently_down {
+            tracked.repeat_timer = None;
+        }
+
+        let mut repeated = false;
+        if let Some(timer) = &mut tracked.repeat_timer {
+            if timer.tick(engine.delta).just_finished() {
+                repeated = true;
+                if timer.mode() == TimerMode::Once {
+                    *timer = Timer::from_seconds(KEY_REPEAT_INTERVAL_SECONDS, TimerMode::Repeating);
+                }
+            }
+        }
+
+        just_pressed || repeated
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GamePhase {
+    Menu,
+    Playing,
+    Paused,
+    GameOver,
+}
+
+#[derive(Resource)]
+struct GameState {
+    phase: GamePhase,
+    high_score: u32,
+    score: u32,
+    ferris_index: i32,
+    spawn_timer: Timer,
+    // `None` when no gamepad backend is available (missing udev/permissions,
+    // containers, CI, ...) so a broken or absent backend just disables gamepad
+    // input instead of taking keyboard play down with it
+    #[cfg(not(target_arch = "wasm32"))]
+    gilrs: Option<Gilrs>,
+    gamepad_south_down: bool,
+    velocity: Vec2,
+    acceleration: Vec2,
+    gravity_on: bool,
+    player_animator: AnimatedSprite,
+    input: InputLayer,
+    // set by `animate_player` after it removes "player" for a frame; applied at the
+    // top of the next `game_logic` call (regardless of phase) once the removal has
+    // actually taken effect
+    player_respawn: Option<PendingPlayerRespawn>,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self {
+            phase: GamePhase::Menu,
+            high_score: 0,
+            score: 0,
+            ferris_index: 0,
+            spawn_timer: Timer::from_seconds(2.0, TimerMode::Repeating),
+            #[cfg(not(target_arch = "wasm32"))]
+            gilrs: match Gilrs::new() {
+                Ok(gilrs) => Some(gilrs),
+                Err(err) => {
+                    eprintln!("gamepad support disabled: {err}");
+                    None
+                }
+            },
+            gamepad_south_down: false,
+            velocity: Vec2::ZERO,
+            acceleration: Vec2::ZERO,
+            gravity_on: false,
+            player_animator: AnimatedSprite::new(
+                vec![SpritePreset::RacingCarBlue, SpritePreset::RacingCarGreen],
+                ANIMATION_FRAME_SECONDS,
+            ),
+            input: InputLayer::default(),
+            player_respawn: None,
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use super::start_game;
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen(start)]
+    pub fn run() {
+        console_error_panic_hook::set_once();
+        start_game();
+    }
+}
+
+/// Builds and runs the game. Shared by the native `main` (in `main.rs`) and the
+/// wasm32 entry point above, so the browser build stays on the exact same setup
+/// as native. Lives in `lib.rs` rather than `main.rs` because wasm-bindgen needs
+/// this crate built as a `cdylib`, which only applies to the library target.
+pub fn start_game() {
+    let mut game = Game::new();
+
+    game.window_settings(Window {
+        title: "Tutorial!".to_string(),
+        ..Default::default()
+    });
+
+    game.audio_manager.play_music(MusicPreset::Classy8Bit, 0.1);
+
+    let player = game.add_sprite("player", SpritePreset::RacingCarBlue);
+
+    // middle of the screen is 0, 0 coordinates
+    player.translation = Vec2::new(0.0, 0.0); // sprite coordinates starting from the center of the sprite
+
+    // player rotation rotates coordinates fo the player sprite
+    // player.rotation = std::f32::consts::FRAC_PI_2;
+    // player.rotation = UP;
+    player.rotation = SOUTH_WEST;
+
+    // scales the sprite size, 1.0 is 100% size, highest layer is 999.0
+    player.scale = 1.0;
+    player.collision = true;
+
+    // sprite layer is default to 0.0
+    player.layer = 0.0;
+
+    let score = game.add_text("score", "Score: 0");
+    score.translation = Vec2::new(520.0, 320.0);
+
+    let high_score = game.add_text("high_score", "High Score: 0");
+    high_score.translation = Vec2::new(-520.0, 320.0);
+
+    let title = game.add_text("title", "Press Enter to Start");
+    title.translation = Vec2::new(0.0, 0.0);
+
+    let paused = game.add_text("paused", "");
+    paused.translation = OFFSCREEN;
+
+    let game_over = game.add_text("game_over", "");
+    game_over.translation = OFFSCREEN;
+
+    game.add_logic(game_logic);
+    game.run(GameState::default());
+}
+
+fn game_logic(engine: &mut Engine, game_state: &mut GameState) {
+    // quit if Q is pressed
+    if engine.keyboard_state.just_pressed(KeyCode::Q) {
+        engine.should_exit = true;
+    }
+
+    // put the player's sprite back under its new preset now that last frame's removal
+    // (see `animate_player`) has actually taken effect and the old entity is gone. This
+    // runs regardless of phase so a respawn is never stranded by a phase change (e.g. the
+    // game ending) between the removal and the next `Playing` frame.
+    if let Some(respawn) = game_state.player_respawn.take() {
+        let player = engine.add_sprite("player", respawn.preset);
+        player.translation = respawn.translation;
+        player.rotation = respawn.rotation;
+        player.scale = respawn.scale;
+        player.layer = respawn.layer;
+        player.collision = respawn.collision;
+    }
+
+    match game_state.phase {
+        GamePhase::Menu => menu_logic(engine, game_state),
+        GamePhase::Playing => playing_logic(engine, game_state),
+        GamePhase::Paused => paused_logic(engine, game_state),
+        GamePhase::GameOver => game_over_logic(engine, game_state),
+    }
+}
+
+fn menu_logic(engine: &mut Engine, game_state: &mut GameState) {
+    if engine.keyboard_state.just_pressed(KeyCode::Return) {
+        let title = engine.texts.get_mut("title").unwrap();
+        title.value.clear();
+        title.translation = OFFSCREEN;
+        game_state.phase = GamePhase::Playing;
+    }
+}
+
+fn paused_logic(engine: &mut Engine, game_state: &mut GameState) {
+    if engine.keyboard_state.just_pressed(KeyCode::P) {
+        let paused = engine.texts.get_mut("paused").unwrap();
+        paused.value.clear();
+        paused.translation = OFFSCREEN;
+        game_state.phase = GamePhase::Playing;
+    }
+}
+
+fn game_over_logic(engine: &mut Engine, game_state: &mut GameState) {
+    if game_state.input.tick(engine, KeyCode::R) {
+        restart(engine, game_state);
+    }
+}
+
+fn restart(engine: &mut Engine, game_state: &mut GameState) {
+    let ferris_labels: Vec<String> = engine
+        .sprites
+        .keys()
+        .filter(|label| label.starts_with("ferris"))
+        .cloned()
+        .collect();
+    for label in ferris_labels {
+        engine.sprites.remove(&label);
+    }
+
+    game_state.score = 0;
+    game_state.velocity = Vec2::ZERO;
+    game_state.acceleration = Vec2::ZERO;
+
+    let score = engine.texts.get_mut("score").unwrap();
+    score.value = "Score: 0".to_string();
+
+    let game_over = engine.texts.get_mut("game_over").unwrap();
+    game_over.value.clear();
+    game_over.translation = OFFSCREEN;
+
+    let player = engine.sprites.get_mut("player").unwrap();
+    player.translation = Vec2::new(0.0, 0.0);
+
+    game_state.phase = GamePhase::Playing;
+}
+
+fn playing_logic(engine: &mut Engine, game_state: &mut GameState) {
+    // pause the game, freezing input and the spawn timer
+    if engine.keyboard_state.just_pressed(KeyCode::P) {
+        let paused = engine.texts.get_mut("paused").unwrap();
+        paused.value = "Paused".to_string();
+        paused.translation = Vec2::new(0.0, 0.0);
+        game_state.phase = GamePhase::Paused;
+        return;
+    }
+
+    // keep text near the edges of the screen
+    //
+    // `time_since_startup_f64` is wall-clock seconds since start regardless of target:
+    // on wasm32 rusty_engine's frame pump is driven by requestAnimationFrame instead
+    // of a native event loop, but it still advances this clock once per pumped frame,
+    // so no adaptation is needed here.
+    let offset = ((engine.time_since_startup_f64 * 3.0).cos() * 5.0) as f32;
+    let score = engine.texts.get_mut("score").unwrap();
+    score.translation.x = engine.window_dimensions.x / 2.0 - 80.0;
+    score.translation.y = engine.window_dimensions.y / 2.0 - 30.0 + offset;
+    let high_score = engine.texts.get_mut("high_score").unwrap();
+    high_score.translation.x = -engine.window_dimensions.x / 2.0 + 110.0;
+    high_score.translation.y = engine.window_dimensions.y / 2.0 - 30.0;
+
+    // handle collisions
+    for event in engine.collision_events.drain(..) {
+        if event.state != CollisionState::Begin || !event.pair.one_starts_with("player") {
+            continue;
+        }
+
+        let ferris_label = [event.pair.0, event.pair.1]
+            .into_iter()
+            .find(|label| label != "player")
+            .expect("a collision pair involving \"player\" always has a non-player label");
+
+        engine.sprites.remove(&ferris_label);
+        game_state.score += 1;
+        let score = engine.texts.get_mut("score").unwrap();
+        score.value = format!("Score: {}", game_state.score);
+
+        if game_state.score > game_state.high_score {
+            game_state.high_score = game_state.score;
+            let high_score = engine.texts.get_mut("high_score").unwrap();
+            high_score.value = format!("High Score: {}", game_state.high_score);
+        }
+        engine.audio_manager.play_sfx(SfxPreset::Minimize1, 0.3);
+    }
+
+    // toggle gravity
+    if engine.keyboard_state.just_pressed(KeyCode::G) {
+        game_state.gravity_on = !game_state.gravity_on;
+    }
+
+    // handle movement: accumulate thrust from input, then integrate with inertia
+    let mut direction = Vec2::ZERO;
+    if engine
+        .keyboard_state
+        .pressed_any(&[KeyCode::Up, KeyCode::W])
+    {
+        direction.y += 1.0;
+    }
+    if engine
+        .keyboard_state
+        .pressed_any(&[KeyCode::Down, KeyCode::S])
+    {
+        direction.y -= 1.0;
+    }
+    if engine
+        .keyboard_state
+        .pressed_any(&[KeyCode::Right, KeyCode::D])
+    {
+        direction.x += 1.0;
+    }
+    if engine
+        .keyboard_state
+        .pressed_any(&[KeyCode::Left, KeyCode::A])
+    {
+        direction.x -= 1.0;
+    }
+
+    // fold the gamepad stick into the same direction vector as the keyboard so both
+    // input sources feed the single integrate->clamp->damp pass below identically
+    let (gamepad_stick, gamepad_south_down) = poll_gamepad_input(game_state);
+    direction += gamepad_stick;
+
+    game_state.acceleration = direction * THRUST;
+    if game_state.gravity_on {
+        game_state.acceleration += GRAVITY;
+    }
+
+    game_state.velocity += game_state.acceleration * engine.delta_f32;
+    game_state.velocity *= DAMPING;
+
+    let player = engine.sprites.get_mut("player").unwrap();
+    player.translation += game_state.velocity * engine.delta_f32;
+
+    // keep the player inside the window, killing velocity on the axis that hit a wall
+    let half_width = engine.window_dimensions.x / 2.0;
+    let half_height = engine.window_dimensions.y / 2.0;
+    if player.translation.x < -half_width {
+        player.translation.x = -half_width;
+        game_state.velocity.x = 0.0;
+    } else if player.translation.x > half_width {
+        player.translation.x = half_width;
+        game_state.velocity.x = 0.0;
+    }
+    if player.translation.y < -half_height {
+        player.translation.y = -half_height;
+        game_state.velocity.y = 0.0;
+    } else if player.translation.y > half_height {
+        player.translation.y = half_height;
+        game_state.velocity.y = 0.0;
+    }
+
+    let is_moving = direction != Vec2::ZERO || game_state.velocity.length_squared() > 1.0;
+
+    // handle mouse input
+    if engine.mouse_state.just_pressed(MouseButton::Left) {
+        if let Some(mouse_location) = engine.mouse_state.location() {
+            spawn_ferris(engine, game_state, mouse_location);
+        }
+    }
+
+    // holding Space spawns ferris at the player's position at a steady cadence
+    if game_state.input.tick(engine, KeyCode::Space) {
+        let player_location = engine.sprites.get("player").unwrap().translation;
+        spawn_ferris(engine, game_state, player_location);
+    }
+
+    if game_state.spawn_timer.tick(engine.delta).just_finished() {
+        let label = format!("ferris{}", game_state.ferris_index);
+        game_state.ferris_index += 1;
+        let ferris = engine.add_sprite(label.clone(), SpritePreset::RacingCarYellow);
+        ferris.translation.x = thread_rng().gen_range(-550.0..550.0);
+        ferris.translation.y = thread_rng().gen_range(-325.0..325.0);
+        ferris.collision = true;
+    }
+
+    // gamepad south button spawns ferris at the player's position, edge-triggered
+    // (the stick axes were already folded into this frame's acceleration above)
+    if gamepad_south_down && !game_state.gamepad_south_down {
+        let player_location = engine.sprites.get("player").unwrap().translation;
+        spawn_ferris(engine, game_state, player_location);
+    }
+    game_state.gamepad_south_down = gamepad_south_down;
+
+    // Reset score (holding R resets repeatedly at the key-repeat cadence)
+    if game_state.input.tick(engine, KeyCode::R) {
+        game_state.score = 0;
+        let score = engine.texts.get_mut("score").unwrap();
+        score.value = "Score: 0".to_string();
+    }
+
+    // run last, after everything above that reads the player's current sprite, since
+    // this can remove "player" for the rest of the frame (see `animate_player`)
+    animate_player(engine, game_state, is_moving);
+
+    // lose if too many ferris pile up uncollected
+    let ferris_count = engine
+        .sprites
+        .keys()
+        .filter(|label| label.starts_with("ferris"))
+        .count();
+    if ferris_count > MAX_FERRIS_ON_SCREEN {
+        let game_over = engine.texts.get_mut("game_over").unwrap();
+        game_over.value = format!(
+            "Game Over! Final Score: {}\nPress R to restart",
+            game_state.score
+        );
+        game_over.translation = Vec2::new(0.0, 0.0);
+        game_state.phase = GamePhase::GameOver;
+    }
+}
+
+fn animate_player(engine: &mut Engine, game_state: &mut GameState, is_moving: bool) {
+    if !is_moving {
+        return;
+    }
+
+    if !game_state.player_animator.timer.tick(engine.delta).just_finished() {
+        return;
+    }
+
+    game_state.player_animator.index =
+        (game_state.player_animator.index + 1) % game_state.player_animator.frames.len();
+    let preset = game_state.player_animator.frames[game_state.player_animator.index];
+
+    // rusty_engine only loads a sprite's texture for an entity when that entity is
+    // spawned, and only spawns one for a label that's genuinely missing from
+    // `engine.sprites` by the time the engine's per-frame sync runs (see `game_logic_sync`
+    // in rusty_engine's `game.rs`). Removing and re-adding "player" within this same
+    // call would leave it present throughout, so the existing entity gets reused and
+    // the texture never changes. Instead, remove it now and defer the respawn (handled
+    // at the top of `game_logic`, regardless of phase) to next frame, once the removal
+    // has actually taken effect and the old entity is gone.
+    let player = engine.sprites.get("player").unwrap();
+    game_state.player_respawn = Some(PendingPlayerRespawn {
+        preset,
+        translation: player.translation,
+        rotation: player.rotation,
+        scale: player.scale,
+        layer: player.layer,
+        collision: player.collision,
+    });
+    engine.sprites.remove("player");
+}
+
+/// Reads this frame's gamepad stick deflection (outside `GAMEPAD_DEADZONE`) and south
+/// button so both can feed this frame's physics and edge-detection the same way the
+/// keyboard does. Gamepad support is native-only: `gilrs` has no wasm32 backend, so
+/// the browser build always reports "nothing pressed" and the keyboard remains the
+/// only input source there.
+#[cfg(target_arch = "wasm32")]
+fn poll_gamepad_input(_game_state: &mut GameState) -> (Vec2, bool) {
+    (Vec2::ZERO, false)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn poll_gamepad_input(game_state: &mut GameState) -> (Vec2, bool) {
+    let Some(gilrs) = game_state.gilrs.as_mut() else {
+        return (Vec2::ZERO, false);
+    };
+
+    // drain pending events so gilrs keeps its internal gamepad state current
+    while gilrs.next_event().is_some() {}
+
+    let Some((_id, gamepad)) = gilrs.gamepads().next() else {
+        return (Vec2::ZERO, false);
+    };
+
+    let mut stick = Vec2::ZERO;
+    let stick_x = gamepad.value(Axis::LeftStickX);
+    let stick_y = gamepad.value(Axis::LeftStickY);
+    if stick_x.abs() > GAMEPAD_DEADZONE {
+        stick.x = stick_x;
+    }
+    if stick_y.abs() > GAMEPAD_DEADZONE {
+        stick.y = stick_y;
+    }
+
+    (stick, gamepad.is_pressed(Button::South))
+}
+
+fn spawn_ferris(engine: &mut Engine, game_state: &mut GameState, location: Vec2) {
+    let label = format!("ferris{}", game_state.ferris_index);
+    game_state.ferris_index += 1;
+    let ferris = engine.add_sprite(label.clone(), SpritePreset::RacingCarYellow);
+    ferris.translation = location;
+    ferris.collision = true;
+}